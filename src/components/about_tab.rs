@@ -0,0 +1,49 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use leptos::prelude::*;
+
+use crate::yaixm::ParseError;
+
+#[component]
+pub fn AboutTab() -> impl IntoView {
+    let diagnostics = use_context::<ReadSignal<Vec<ParseError>>>().expect("diagnostics context");
+
+    view! {
+        <div class="content">
+            <p>
+                "ASSelect lets you choose which UK airspace to include in a moving-map "
+                "data file, generated from the current ASP/YAIXM airspace release."
+            </p>
+        </div>
+
+        <div class="content" class:is-hidden=move || diagnostics.get().is_empty()>
+            <h2 class="subtitle">{"Diagnostics"}</h2>
+            <p>
+                "The following records in the current airspace release could not be "
+                "parsed, and were skipped from the generated data:"
+            </p>
+            <ul>
+                {move || {
+                    diagnostics
+                        .get()
+                        .into_iter()
+                        .map(|e| view! { <li>{e.to_string()}</li> })
+                        .collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
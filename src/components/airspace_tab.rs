@@ -0,0 +1,31 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use leptos::prelude::*;
+
+#[component]
+pub fn AirspaceTab(gliding_sites: Vec<String>) -> impl IntoView {
+    let site_rows = gliding_sites
+        .into_iter()
+        .map(|site| view! { <label class="checkbox block">{site}</label> })
+        .collect_view();
+
+    view! {
+        <div class="box">
+            <h2 class="subtitle">{"Gliding Sites"}</h2>
+            {site_rows}
+        </div>
+    }
+}
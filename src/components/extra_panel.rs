@@ -0,0 +1,55 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use leptos::prelude::*;
+
+use crate::settings::{ExtraType, Settings};
+
+#[component]
+pub fn ExtraPanel(names: Vec<String>, id: ExtraType) -> impl IntoView {
+    let set_settings = use_context::<WriteSignal<Settings>>().expect("settings context");
+
+    let rows = names
+        .into_iter()
+        .map(|name| {
+            let name_on = name.clone();
+            let name_off = name.clone();
+            view! {
+                <label class="checkbox block">
+                    <input
+                        type="checkbox"
+                        on:change=move |ev| {
+                            let checked = event_target_checked(&ev);
+                            set_settings
+                                .update(|s| {
+                                    let entry = s.extra.entry(id).or_default();
+                                    if checked {
+                                        if !entry.contains(&name_on) {
+                                            entry.push(name_on.clone());
+                                        }
+                                    } else {
+                                        entry.retain(|n| n != &name_off);
+                                    }
+                                });
+                        }
+                    />
+                    {name}
+                </label>
+            }
+        })
+        .collect_view();
+
+    view! { <div class="block">{rows}</div> }
+}
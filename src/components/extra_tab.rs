@@ -0,0 +1,34 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use leptos::prelude::*;
+
+use crate::settings::ExtraType;
+
+#[component]
+pub fn ExtraTab(names: Vec<String>, ids: Vec<ExtraType>, children: Children) -> impl IntoView {
+    let headings = names
+        .into_iter()
+        .zip(ids)
+        .map(|(name, _id)| view! { <h2 class="subtitle">{name}</h2> })
+        .collect_view();
+
+    view! {
+        <div class="box">
+            {headings}
+            {children()}
+        </div>
+    }
+}
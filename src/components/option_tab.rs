@@ -0,0 +1,138 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use leptos::prelude::*;
+
+use crate::components::profile_panel::ProfilePanel;
+use crate::settings::{OutputFormat, Settings};
+
+#[component]
+pub fn OptionTab() -> impl IntoView {
+    let settings = use_context::<ReadSignal<Settings>>().expect("settings context");
+    let set_settings = use_context::<WriteSignal<Settings>>().expect("settings context");
+
+    view! {
+        <ProfilePanel/>
+
+        <div class="box">
+            <div class="field">
+                <label class="label">{"Format"}</label>
+                <div class="control">
+                    <label class="radio">
+                        <input
+                            type="radio"
+                            name="format"
+                            checked=move || settings().format == OutputFormat::OpenAir
+                            on:change=move |_| set_settings.update(|s| s.format = OutputFormat::OpenAir)
+                        />
+                        {" OpenAir"}
+                    </label>
+                    <label class="radio">
+                        <input
+                            type="radio"
+                            name="format"
+                            checked=move || settings().format == OutputFormat::GeoJson
+                            on:change=move |_| set_settings.update(|s| s.format = OutputFormat::GeoJson)
+                        />
+                        {" GeoJSON"}
+                    </label>
+                </div>
+            </div>
+
+            <div class="field">
+                <label class="label">{"Highest altitude (feet)"}</label>
+                <div class="control">
+                    <input
+                        class="input"
+                        type="number"
+                        prop:value=move || settings().max_level.to_string()
+                        on:input=move |ev| {
+                            if let Ok(level) = event_target_value(&ev).parse::<u16>() {
+                                set_settings.update(|s| s.max_level = level);
+                            }
+                        }
+                    />
+                </div>
+            </div>
+
+            <label class="checkbox block">
+                <input
+                    type="checkbox"
+                    prop:checked=move || settings().atz
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        set_settings.update(|s| s.atz = checked);
+                    }
+                />
+                {" Include ATZ"}
+            </label>
+
+            <label class="checkbox block">
+                <input
+                    type="checkbox"
+                    prop:checked=move || settings().radio
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        set_settings.update(|s| s.radio = checked);
+                    }
+                />
+                {" Include radio frequencies"}
+            </label>
+
+            <label class="checkbox block">
+                <input
+                    type="checkbox"
+                    prop:checked=move || settings().north
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        set_settings.update(|s| s.north = checked);
+                    }
+                />
+                {" Scotland/Northern England only"}
+            </label>
+
+            <label class="checkbox block">
+                <input
+                    type="checkbox"
+                    prop:checked=move || settings().simplify
+                    on:change=move |ev| {
+                        let checked = event_target_checked(&ev);
+                        set_settings.update(|s| s.simplify = checked);
+                    }
+                />
+                {" Simplify geometry, for point-limited GPS devices"}
+            </label>
+
+            <div class="field" class:is-hidden=move || !settings().simplify>
+                <label class="label">{"Simplify tolerance (km)"}</label>
+                <div class="control">
+                    <input
+                        class="input"
+                        type="number"
+                        step="0.1"
+                        min="0.1"
+                        max="2"
+                        prop:value=move || settings().tolerance_km.to_string()
+                        on:input=move |ev| {
+                            if let Ok(tolerance_km) = event_target_value(&ev).parse::<f64>() {
+                                set_settings.update(|s| s.tolerance_km = tolerance_km);
+                            }
+                        }
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
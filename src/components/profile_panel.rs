@@ -0,0 +1,179 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+// Switch between named Settings profiles, and import/export/share a
+// Settings profile as a downloadable file, file upload, or copyable link.
+use gloo::file::futures::read_as_text;
+use gloo::file::{Blob, File as GlooFile, ObjectUrl};
+use leptos::html::A;
+use leptos::prelude::*;
+use leptos::web_sys;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::HtmlInputElement;
+
+use crate::profiles::{Profiles, DEFAULT_PROFILE};
+use crate::settings::Settings;
+
+#[component]
+pub fn ProfilePanel() -> impl IntoView {
+    let settings = use_context::<ReadSignal<Settings>>().expect("settings context");
+    let set_settings = use_context::<WriteSignal<Settings>>().expect("settings context");
+    let profiles = use_context::<Signal<Profiles>>().expect("profiles context");
+    let set_profiles = use_context::<WriteSignal<Profiles>>().expect("profiles context");
+
+    // Which profile the select box shows as active - kept separately from
+    // Settings, since editing options doesn't change which profile you're
+    // nominally working from
+    let (active_profile, set_active_profile) = signal(DEFAULT_PROFILE.to_string());
+
+    let (new_profile_name, set_new_profile_name) = signal(String::new());
+
+    let download_node_ref = NodeRef::<A>::new();
+    let upload_node_ref = NodeRef::<leptos::html::Input>::new();
+
+    let select_profile = move |ev| {
+        let name = event_target_value(&ev);
+        if let Some(profile) = profiles.get_untracked().get(&name) {
+            set_settings.set(profile.clone());
+            set_active_profile.set(name);
+        }
+    };
+
+    let save_profile = move |_| {
+        let name = new_profile_name.get_untracked();
+        if name.is_empty() {
+            return;
+        }
+        set_profiles.update(|p| {
+            p.insert(name.clone(), settings.get_untracked());
+        });
+        set_active_profile.set(name);
+        set_new_profile_name.set(String::new());
+    };
+
+    let export_profile = move |_| {
+        let json = serde_json::to_string_pretty(&settings.get_untracked()).unwrap_or_default();
+        let blob = Blob::new(json.as_str());
+        let object_url = ObjectUrl::from(blob);
+
+        let a = download_node_ref.get().unwrap();
+        a.set_download("asselect-settings.json");
+        a.set_href(&object_url);
+        a.click();
+    };
+
+    let import_profile = move |_| {
+        let Some(input) = upload_node_ref.get() else {
+            return;
+        };
+        let Some(file_list) = input.files() else {
+            return;
+        };
+        let Some(file) = file_list.get(0) else {
+            return;
+        };
+
+        spawn_local(async move {
+            if let Ok(text) = read_as_text(&GlooFile::from(file)).await {
+                if let Ok(imported) = serde_json::from_str::<Settings>(&text) {
+                    set_settings.set(imported);
+                }
+            }
+        });
+    };
+
+    let copy_link = move |_| {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let Ok(location) = window.location().href() else {
+            return;
+        };
+        let Ok(url) = web_sys::Url::new(&location) else {
+            return;
+        };
+        url.search_params()
+            .set("s", &settings.get_untracked().to_query_value());
+
+        let clipboard = window.navigator().clipboard();
+        let promise = clipboard.write_text(&url.href());
+        spawn_local(async move {
+            let _ = JsFuture::from(promise).await;
+        });
+    };
+
+    view! {
+        <div class="box">
+            <div class="field">
+                <label class="label">{"Profile"}</label>
+                <div class="control">
+                    <div class="select">
+                        <select on:change=select_profile prop:value=move || active_profile.get()>
+                            {move || {
+                                profiles
+                                    .get()
+                                    .keys()
+                                    .cloned()
+                                    .map(|name| view! { <option value=name.clone()>{name}</option> })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </div>
+                </div>
+            </div>
+
+            <div class="field has-addons">
+                <div class="control is-expanded">
+                    <input
+                        class="input"
+                        type="text"
+                        placeholder="Profile name"
+                        prop:value=move || new_profile_name()
+                        on:input=move |ev| set_new_profile_name(event_target_value(&ev))
+                    />
+                </div>
+                <div class="control">
+                    <button class="button" type="button" on:click=save_profile>
+                        {"Save as profile"}
+                    </button>
+                </div>
+            </div>
+
+            <div class="field is-grouped">
+                <div class="control">
+                    <button class="button" type="button" on:click=export_profile>
+                        {"Export"}
+                    </button>
+                </div>
+                <div class="control">
+                    <input
+                        node_ref=upload_node_ref
+                        class="file-input"
+                        type="file"
+                        accept="application/json"
+                        on:change=import_profile
+                    />
+                </div>
+                <div class="control">
+                    <button class="button" type="button" on:click=copy_link>
+                        {"Copy link"}
+                    </button>
+                </div>
+            </div>
+
+            <a hidden node_ref=download_node_ref></a>
+        </div>
+    }
+}
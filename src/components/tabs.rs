@@ -0,0 +1,50 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use leptos::prelude::*;
+
+#[component]
+pub fn Tabs(tab_names: Vec<String>, children: Children) -> impl IntoView {
+    let (active, set_active) = signal(0usize);
+
+    let tab_links = tab_names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            view! {
+                <li class:is-active=move || active() == i>
+                    <a on:click=move |_| set_active(i)>{name}</a>
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="tabs">
+            <ul>{tab_links}</ul>
+        </div>
+
+        <div class="tab-content">
+            {children()
+                .nodes
+                .into_iter()
+                .enumerate()
+                .map(|(i, child)| {
+                    view! { <div class:is-hidden=move || active() != i>{child}</div> }
+                })
+                .collect_view()}
+        </div>
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use std::f64::consts::PI;
+
+use serde_json::{json, Value};
+
+use super::{volume_wanted, wanted};
+use crate::settings::Settings;
+use crate::yaixm::{latlon_to_degrees, radius_to_metres, Arc, Boundary, IcaoType, ParseError, Volume, Yaixm};
+
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+const STEP_DEG: f64 = 2.0;
+const MIN_CIRCLE_POINTS: usize = 36;
+
+// Angular radius, in degrees, subtended by a circle of the given radius
+fn angular_radius_deg(radius_m: f64) -> f64 {
+    (radius_m / EARTH_RADIUS_M) * 180.0 / PI
+}
+
+// Point at polar angle `theta_deg` (degrees) on a circle of angular radius
+// `rad_deg` about (clat, clon), using a local planar approximation
+fn polar_point(clat: f64, clon: f64, rad_deg: f64, theta_deg: f64) -> (f64, f64) {
+    let theta = theta_deg.to_radians();
+    let lat = clat + rad_deg * theta.sin();
+    let lon = clon + (rad_deg / clat.to_radians().cos()) * theta.cos();
+    (lat, lon)
+}
+
+// Polar angle, in degrees, of (lat, lon) about (clat, clon)
+fn bearing_deg(clat: f64, clon: f64, lat: f64, lon: f64) -> f64 {
+    (lat - clat)
+        .atan2((lon - clon) * clat.to_radians().cos())
+        .to_degrees()
+}
+
+fn tessellate_circle(clat: f64, clon: f64, radius_m: f64) -> Vec<(f64, f64)> {
+    let rad_deg = angular_radius_deg(radius_m);
+    let n = ((360.0 / STEP_DEG) as usize).max(MIN_CIRCLE_POINTS);
+
+    (0..=n)
+        .map(|i| polar_point(clat, clon, rad_deg, 360.0 * (i as f64) / (n as f64)))
+        .collect()
+}
+
+// Sweep from the current pen position to the arc's end point, around
+// `centre`, in the `dir` ("cw"/"ccw") direction
+fn tessellate_arc(feature: &str, pen: (f64, f64), arc: &Arc) -> Result<Vec<(f64, f64)>, ParseError> {
+    let (clat, clon) = latlon_to_degrees(feature, &arc.centre)?;
+    let rad_deg = angular_radius_deg(radius_to_metres(feature, &arc.radius)?);
+    let (end_lat, end_lon) = latlon_to_degrees(feature, &arc.to)?;
+
+    let start = bearing_deg(clat, clon, pen.0, pen.1);
+    let mut end = bearing_deg(clat, clon, end_lat, end_lon);
+
+    let mut points = Vec::new();
+
+    if arc.dir == "cw" {
+        if end > start {
+            end -= 360.0;
+        }
+        let mut theta = start;
+        while theta > end {
+            points.push(polar_point(clat, clon, rad_deg, theta));
+            theta -= STEP_DEG;
+        }
+    } else {
+        if end < start {
+            end += 360.0;
+        }
+        let mut theta = start;
+        while theta < end {
+            points.push(polar_point(clat, clon, rad_deg, theta));
+            theta += STEP_DEG;
+        }
+    }
+    points.push((end_lat, end_lon));
+    Ok(points)
+}
+
+// Tessellate a volume's boundary into a single closed ring of (lat, lon)
+// vertices, suitable for a GeoJSON polygon. Stops at the first coordinate
+// or radius that fails to parse.
+pub(crate) fn tessellate_boundary(
+    feature: &str,
+    boundary: &[Boundary],
+) -> Result<Vec<(f64, f64)>, ParseError> {
+    let mut points: Vec<(f64, f64)> = Vec::new();
+
+    for element in boundary {
+        match element {
+            Boundary::Line(coords) => {
+                for coord in coords {
+                    points.push(latlon_to_degrees(feature, coord)?);
+                }
+            }
+            Boundary::Circle(circle) => {
+                let (clat, clon) = latlon_to_degrees(feature, &circle.centre)?;
+                let radius_m = radius_to_metres(feature, &circle.radius)?;
+                points.extend(tessellate_circle(clat, clon, radius_m));
+            }
+            Boundary::Arc(arc) => {
+                let pen = *points.last().unwrap_or(&(0.0, 0.0));
+                points.extend(tessellate_arc(feature, pen, arc)?);
+            }
+        }
+    }
+
+    match (points.first(), points.last()) {
+        (Some(&first), Some(&last)) if first != last => points.push(first),
+        _ => (),
+    }
+
+    Ok(points)
+}
+
+// Canonical ICAO type code (e.g. "ATZ", "D_OTHER"), not the Rust variant
+// name that `{:?}` would give
+fn icao_type_str(icao_type: &IcaoType) -> String {
+    match serde_json::to_value(icao_type) {
+        Ok(Value::String(s)) => s,
+        _ => format!("{icao_type:?}"),
+    }
+}
+
+fn volume_feature(
+    name: &str,
+    icao_type: &str,
+    icao_class: &str,
+    vol: &Volume,
+) -> Result<Value, ParseError> {
+    let ring = tessellate_boundary(name, &vol.boundary)?;
+    let coordinates: Vec<[f64; 2]> = ring.iter().map(|(lat, lon)| [*lon, *lat]).collect();
+
+    Ok(json!({
+        "type": "Feature",
+        "properties": {
+            "name": name,
+            "icao_type": icao_type,
+            "icao_class": icao_class,
+            "lower": vol.lower,
+            "upper": vol.upper,
+        },
+        "geometry": {
+            "type": "Polygon",
+            "coordinates": [coordinates],
+        }
+    }))
+}
+
+// Build a GeoJSON FeatureCollection from YAIXM airspace, filtered by the
+// user's settings. Volumes that fail to parse are skipped and reported
+// rather than aborting the whole conversion.
+pub fn geojson(yaixm: &Yaixm, settings: &Settings) -> (String, Vec<ParseError>) {
+    let mut features = Vec::new();
+    let mut errors = Vec::new();
+
+    for feature in yaixm.airspace.iter().filter(|f| wanted(f, settings)) {
+        for vol in feature
+            .geometry
+            .iter()
+            .filter(|v| volume_wanted(&feature.name, v, settings))
+        {
+            let icao_class = vol
+                .icao_class
+                .or(feature.icao_class)
+                .map(|c| format!("{c:?}"))
+                .unwrap_or_else(|| "G".to_string());
+
+            match volume_feature(&feature.name, &icao_type_str(&feature.icao_type), &icao_class, vol) {
+                Ok(value) => features.push(value),
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+
+    (
+        json!({
+            "type": "FeatureCollection",
+            "features": features,
+        })
+        .to_string(),
+        errors,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yaixm::Arc;
+
+    #[test]
+    fn tessellate_circle_closes_and_meets_point_floor() {
+        let points = tessellate_circle(10.0, 20.0, 10_000.0);
+        assert!(points.len() >= MIN_CIRCLE_POINTS);
+
+        let first = points[0];
+        let last = *points.last().unwrap();
+        assert!((first.0 - last.0).abs() < 1e-9);
+        assert!((first.1 - last.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tessellate_arc_ccw_sweeps_to_exact_end_point() {
+        let arc = Arc {
+            centre: "000000N 0000000E".to_string(),
+            dir: "ccw".to_string(),
+            radius: "60 nm".to_string(),
+            to: "010000N 0000000E".to_string(),
+        };
+        // Pen at bearing 0 (due "east" in this module's theta convention)
+        let points = tessellate_arc("test", (0.0, 1.0), &arc).unwrap();
+
+        assert!(points.len() > 1);
+        assert_eq!(*points.last().unwrap(), (1.0, 0.0));
+    }
+
+    #[test]
+    fn tessellate_arc_cw_sweeps_to_exact_end_point() {
+        let arc = Arc {
+            centre: "000000N 0000000E".to_string(),
+            dir: "cw".to_string(),
+            radius: "60 nm".to_string(),
+            to: "010000S 0000000E".to_string(),
+        };
+        let points = tessellate_arc("test", (0.0, 1.0), &arc).unwrap();
+
+        assert!(points.len() > 1);
+        assert_eq!(*points.last().unwrap(), (-1.0, 0.0));
+    }
+
+    #[test]
+    fn tessellate_arc_propagates_parse_error() {
+        let arc = Arc {
+            centre: "not a coordinate".to_string(),
+            dir: "ccw".to_string(),
+            radius: "60 nm".to_string(),
+            to: "010000N 0000000E".to_string(),
+        };
+        assert!(tessellate_arc("test", (0.0, 1.0), &arc).is_err());
+    }
+}
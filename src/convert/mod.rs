@@ -0,0 +1,207 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use std::fmt::Write;
+
+use crate::settings::{ExtraType, Settings};
+use crate::yaixm::{
+    latlon_to_degrees, radius_to_metres, Boundary, Feature, IcaoClass, IcaoType, ParseError, Volume, Yaixm,
+};
+
+// Rough dividing latitude between Scotland/Northern England and the rest
+// of Great Britain, for the "north only" filter
+const NORTH_LATITUDE_DEG: f64 = 54.0;
+
+pub mod geojson;
+mod simplify;
+
+pub use geojson::geojson;
+
+use geojson::tessellate_boundary;
+use simplify::simplify_ring;
+
+// Tim Newport-Peace "OpenAir" format converter
+fn level_str(level: &str) -> String {
+    if level == "SFC" || level == "GND" {
+        "SFC".to_string()
+    } else {
+        level.to_string()
+    }
+}
+
+// Render one volume as OpenAir text, or the ParseError of the first
+// coordinate or radius that failed to parse
+fn openair_volume(name: &str, icao_class: &str, vol: &Volume, settings: &Settings) -> Result<String, ParseError> {
+    let mut out = String::new();
+
+    writeln!(out, "AC {icao_class}").ok();
+    writeln!(out, "AN {name}").ok();
+    writeln!(out, "AL {}", level_str(&vol.lower)).ok();
+    writeln!(out, "AH {}", level_str(&vol.upper)).ok();
+
+    if settings.radio {
+        if let Some(frequency) = vol.frequency {
+            writeln!(out, "AF {frequency:.3}").ok();
+        }
+    }
+
+    if settings.simplify {
+        let ring = simplify_ring(tessellate_boundary(name, &vol.boundary)?, settings.tolerance_km);
+        for (lat, lon) in ring {
+            writeln!(out, "DP {lat:.6} {lon:.6}").ok();
+        }
+        return Ok(out);
+    }
+
+    for boundary in &vol.boundary {
+        match boundary {
+            Boundary::Line(points) => {
+                for point in points {
+                    let (lat, lon) = latlon_to_degrees(name, point)?;
+                    writeln!(out, "DP {lat:.6} {lon:.6}").ok();
+                }
+            }
+            Boundary::Circle(circle) => {
+                let (clat, clon) = latlon_to_degrees(name, &circle.centre)?;
+                let radius_nm = radius_to_metres(name, &circle.radius)? / 1852.0;
+                writeln!(out, "V X={clat:.6} {clon:.6}").ok();
+                writeln!(out, "DC {radius_nm:.2}").ok();
+            }
+            Boundary::Arc(arc) => {
+                let (clat, clon) = latlon_to_degrees(name, &arc.centre)?;
+                let (tlat, tlon) = latlon_to_degrees(name, &arc.to)?;
+                let dir = if arc.dir == "ccw" { "-" } else { "+" };
+                writeln!(out, "V D={dir}").ok();
+                writeln!(out, "V X={clat:.6} {clon:.6}").ok();
+                writeln!(out, "DB {tlat:.6} {tlon:.6}").ok();
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+fn icao_class_str(feature: &Feature, vol: &Volume) -> &'static str {
+    match vol.icao_class.or(feature.icao_class) {
+        Some(IcaoClass::A) => "A",
+        Some(IcaoClass::B) => "B",
+        Some(IcaoClass::C) => "C",
+        Some(IcaoClass::D) => "D",
+        Some(IcaoClass::E) => "E",
+        Some(IcaoClass::F) => "F",
+        _ => "G",
+    }
+}
+
+pub(crate) fn wanted(feature: &Feature, settings: &Settings) -> bool {
+    if !settings.atz && feature.icao_type == IcaoType::Atz {
+        return false;
+    }
+    true
+}
+
+// Parse a YAIXM level string ("SFC", "GND", "FL065", "3500ft") to feet, for
+// comparison against `Settings::max_level`. Returns None for anything else
+// (e.g. "UNL"), which is treated as unbounded and never filtered out.
+fn level_to_feet(level: &str) -> Option<u16> {
+    let level = level.trim();
+    if level == "SFC" || level == "GND" {
+        return Some(0);
+    }
+    if let Some(fl) = level.strip_prefix("FL") {
+        return fl.trim().parse::<u16>().ok()?.checked_mul(100);
+    }
+    let digits: String = level.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+// First coordinate of a boundary, used as a stand-in for the volume's
+// location when applying the "north only" filter. Returns None (rather
+// than a ParseError) on a malformed coordinate, so a filter check never
+// masks a parse failure that the actual conversion will separately report.
+fn first_latlon(feature: &str, boundary: &[Boundary]) -> Option<(f64, f64)> {
+    let latlon = match boundary.first()? {
+        Boundary::Line(points) => points.first()?,
+        Boundary::Circle(circle) => &circle.centre,
+        Boundary::Arc(arc) => &arc.centre,
+    };
+    latlon_to_degrees(feature, latlon).ok()
+}
+
+// Per-volume filtering on altitude ceiling and "north only", applied in
+// addition to the feature-level `wanted` filter
+pub(crate) fn volume_wanted(feature: &str, vol: &Volume, settings: &Settings) -> bool {
+    if let Some(lower_ft) = level_to_feet(&vol.lower) {
+        if lower_ft > settings.max_level {
+            return false;
+        }
+    }
+
+    if settings.north {
+        if let Some((lat, _)) = first_latlon(feature, &vol.boundary) {
+            if lat < NORTH_LATITUDE_DEG {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+// Build OpenAir text from YAIXM airspace, filtered by the user's settings.
+// Volumes that fail to parse are skipped and reported, rather than
+// aborting the whole conversion.
+pub fn openair(yaixm: &Yaixm, settings: &Settings, user_agent: &str) -> (String, Vec<ParseError>) {
+    let mut out = String::new();
+    let mut errors = Vec::new();
+
+    writeln!(out, "* Generated by ASSelect").ok();
+    writeln!(out, "* User agent: {user_agent}").ok();
+    writeln!(out).ok();
+
+    for feature in yaixm.airspace.iter().filter(|f| wanted(f, settings)) {
+        for vol in feature
+            .geometry
+            .iter()
+            .filter(|v| volume_wanted(&feature.name, v, settings))
+        {
+            match openair_volume(&feature.name, icao_class_str(feature, vol), vol, settings) {
+                Ok(text) => {
+                    out.push_str(&text);
+                    writeln!(out).ok();
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+
+    for extra_type in [ExtraType::Rat, ExtraType::Loa, ExtraType::Wave] {
+        if let Some(names) = settings.extra.get(&extra_type) {
+            for feature in yaixm.rat.iter().filter(|f| names.contains(&f.name)) {
+                for vol in &feature.geometry {
+                    match openair_volume(&feature.name, "Q", vol, settings) {
+                        Ok(text) => {
+                            out.push_str(&text);
+                            writeln!(out).ok();
+                        }
+                        Err(e) => errors.push(e),
+                    }
+                }
+            }
+        }
+    }
+
+    (out, errors)
+}
@@ -0,0 +1,148 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+// Douglas-Peucker polyline reduction, so that tessellated boundaries fit
+// the point budget of GPS moving-map units that cap vertices per polygon.
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+// Local equirectangular projection, metres, so that the epsilon tolerance
+// is in real-world units rather than degrees
+fn project(lat: f64, lon: f64) -> (f64, f64) {
+    let x = lon.to_radians() * lat.to_radians().cos() * EARTH_RADIUS_M;
+    let y = lat.to_radians() * EARTH_RADIUS_M;
+    (x, y)
+}
+
+fn perpendicular_distance_m(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (px, py) = project(point.0, point.1);
+    let (sx, sy) = project(start.0, start.1);
+    let (ex, ey) = project(end.0, end.1);
+
+    let dx = ex - sx;
+    let dy = ey - sy;
+
+    if dx == 0.0 && dy == 0.0 {
+        return ((px - sx).powi(2) + (py - sy).powi(2)).sqrt();
+    }
+
+    (dy * px - dx * py + ex * sy - ey * sx).abs() / (dx * dx + dy * dy).sqrt()
+}
+
+// Douglas-Peucker reduction of a (lat, lon) polyline. Never drops the
+// first or last vertex.
+fn douglas_peucker(points: &[(f64, f64)], epsilon_m: f64) -> Vec<(f64, f64)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let start = points[0];
+    let end = points[points.len() - 1];
+
+    let (index, dist) = points[1..points.len() - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (i + 1, perpendicular_distance_m(p, start, end)))
+        .fold((0, 0.0_f64), |furthest, candidate| {
+            if candidate.1 > furthest.1 {
+                candidate
+            } else {
+                furthest
+            }
+        });
+
+    if dist > epsilon_m {
+        let mut reduced = douglas_peucker(&points[..=index], epsilon_m);
+        reduced.pop();
+        reduced.extend(douglas_peucker(&points[index..], epsilon_m));
+        reduced
+    } else {
+        vec![start, end]
+    }
+}
+
+// Length, in metres, of the diagonal of the ring's bounding box - a cheap
+// stand-in for its physical size, regardless of how densely it's tessellated
+fn ring_extent_m(ring: &[(f64, f64)]) -> f64 {
+    let projected: Vec<(f64, f64)> = ring.iter().map(|&(lat, lon)| project(lat, lon)).collect();
+
+    let (min_x, max_x) = projected
+        .iter()
+        .map(|&(x, _)| x)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(mn, mx), x| (mn.min(x), mx.max(x)));
+    let (min_y, max_y) = projected
+        .iter()
+        .map(|&(_, y)| y)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(mn, mx), y| (mn.min(y), mx.max(y)));
+
+    ((max_x - min_x).powi(2) + (max_y - min_y).powi(2)).sqrt()
+}
+
+// Simplify a closed ring to `tolerance_km`, leaving rings whose physical
+// size is at or below the tolerance untouched (so a small circle or arc
+// isn't collapsed just because the tessellator gave it many points), and
+// always keeping the ring closed.
+pub(crate) fn simplify_ring(ring: Vec<(f64, f64)>, tolerance_km: f64) -> Vec<(f64, f64)> {
+    let epsilon_m = tolerance_km * 1000.0;
+
+    if ring_extent_m(&ring) <= epsilon_m {
+        return ring;
+    }
+
+    let simplified = douglas_peucker(&ring, epsilon_m);
+
+    // A closed ring (first vertex == last) with every interior vertex
+    // within epsilon of that closure point reduces to `[start, end]` with
+    // start == end - a degenerate, zero-area "polygon". Fall back to the
+    // unsimplified ring rather than hand that to downstream consumers.
+    if simplified.len() < 3 {
+        return ring;
+    }
+
+    simplified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn douglas_peucker_drops_collinear_points() {
+        let points = vec![(0.0, 0.0), (0.0, 1.0), (0.0, 2.0)];
+        assert_eq!(douglas_peucker(&points, 1.0), vec![(0.0, 0.0), (0.0, 2.0)]);
+    }
+
+    #[test]
+    fn douglas_peucker_keeps_point_beyond_tolerance() {
+        // Offset roughly 1.1km from the chord, well beyond the 10m epsilon
+        let points = vec![(0.0, 0.0), (0.01, 1.0), (0.0, 2.0)];
+        assert_eq!(douglas_peucker(&points, 10.0).len(), 3);
+    }
+
+    #[test]
+    fn simplify_ring_leaves_small_ring_untouched() {
+        let ring = vec![(0.0, 0.0), (0.001, 0.001), (0.0, 0.002), (0.0, 0.0)];
+        assert_eq!(simplify_ring(ring.clone(), 2.0), ring);
+    }
+
+    #[test]
+    fn simplify_ring_falls_back_when_simplification_would_degenerate() {
+        // Every vertex sits within epsilon of the (degenerate) start/end
+        // chord, but they're spread far enough apart from each other that
+        // the ring's bounding-box extent still clears the gate - the
+        // scenario that used to collapse to a single repeated point.
+        let ring = vec![(0.0, 0.0), (0.5, 0.5), (-0.5, -0.5), (0.0, 0.0)];
+        assert_eq!(simplify_ring(ring.clone(), 100.0), ring);
+    }
+}
@@ -26,12 +26,14 @@ use components::{
     about_tab::AboutTab, airspace_tab::AirspaceTab, extra_panel::ExtraPanel, extra_tab::ExtraTab,
     notam_tab::NotamTab, option_tab::OptionTab, tabs::Tabs,
 };
-use convert::openair;
-use settings::{ExtraType, Overlay, Settings};
-use yaixm::{gliding_sites, loa_names, rat_names, wave_names, Yaixm};
+use convert::{geojson, openair};
+use profiles::{default_profiles, Profiles};
+use settings::{ExtraType, Overlay, OutputFormat, Settings};
+use yaixm::{gliding_sites, loa_names, rat_names, wave_names, ParseError, Yaixm};
 
 mod components;
 mod convert;
+mod profiles;
 mod settings;
 mod yaixm;
 
@@ -58,13 +60,24 @@ fn App() -> impl IntoView {
         }
     });
 
+    // A shared link encodes Settings in the "s" query parameter
+    let initial_settings = settings_from_url();
+
     move || match async_yaixm.get().as_deref() {
         Some(resource) => match resource {
-            Some(yaixm) => {
-                view! { <MainView yaixm=yaixm.clone() overlay=async_overlay /> }
+            Ok(yaixm) => {
+                view! {
+                    <MainView
+                        yaixm=yaixm.clone()
+                        overlay=async_overlay
+                        initial_settings=initial_settings.clone()
+                    />
+                }
             }
             .into_any(),
-            None => p().child("Error getting airspace data").into_any(),
+            Err(message) => p()
+                .child(format!("Error getting airspace data: {message}"))
+                .into_any(),
         },
         None => p()
             .child("Getting airspace data, please wait...")
@@ -72,17 +85,52 @@ fn App() -> impl IntoView {
     }
 }
 
+// Settings carried in the page's "s" query parameter, e.g. from a shared
+// "copy link" URL
+fn settings_from_url() -> Option<Settings> {
+    let href = web_sys::window()?.location().href().ok()?;
+    let url = web_sys::Url::new(&href).ok()?;
+    let value = url.search_params().get("s")?;
+    Settings::from_query_value(&value)
+}
+
 #[component]
-fn MainView(yaixm: Yaixm, overlay: LocalResource<OverlayData>) -> impl IntoView {
+fn MainView(
+    yaixm: Yaixm,
+    overlay: LocalResource<OverlayData>,
+    initial_settings: Option<Settings>,
+) -> impl IntoView {
     // Local settings storage
     let (local_settings, set_local_settings, _) =
         use_local_storage::<Settings, JsonSerdeCodec>("settings");
 
-    // Make copy of settings so store value is only updated on download
-    let (settings, set_settings) = signal(local_settings.get_untracked());
+    // Make copy of settings so store value is only updated on download.
+    // A URL-supplied "s" parameter wins over what's in local storage.
+    let (settings, set_settings) =
+        signal(initial_settings.unwrap_or_else(|| local_settings.get_untracked()));
     provide_context(settings);
     provide_context(set_settings);
 
+    // Named, switchable Settings profiles
+    let (profiles, set_profiles, _) = use_local_storage::<Profiles, JsonSerdeCodec>("profiles");
+    if profiles.get_untracked().is_empty() {
+        set_profiles.set(default_profiles());
+    }
+    provide_context(profiles);
+    provide_context(set_profiles);
+
+    // Volumes that failed to parse on the last conversion, surfaced in the
+    // diagnostics section of AboutTab and as a banner here. Seeded as soon
+    // as the release loads (below), not only once the user clicks "Get
+    // Airspace", so a malformed AIRAC release is flagged immediately.
+    let (diagnostics, set_diagnostics) = signal(Vec::<ParseError>::new());
+    provide_context(diagnostics);
+
+    {
+        let (_, errors) = openair(&yaixm, &settings.get_untracked(), "");
+        set_diagnostics.set(errors);
+    }
+
     // Release note modal display control
     let (modal, set_modal) = signal(false);
 
@@ -127,31 +175,43 @@ fn MainView(yaixm: Yaixm, overlay: LocalResource<OverlayData>) -> impl IntoView
             .and_then(|w| w.navigator().user_agent().ok())
             .unwrap_or_default();
 
-        // Create OpenAir data
-        let oa = openair(&yaixm, &settings.get_untracked(), &user_agent);
+        let (data, filename) = match settings.get_untracked().format {
+            OutputFormat::OpenAir => {
+                // Create OpenAir data
+                let (oa, errors) = openair(&yaixm, &settings.get_untracked(), &user_agent);
+                set_diagnostics.set(errors);
 
-        // Get overlay data
-        let od = if let Some(overlay_setting) = settings().overlay {
-            if let Some(overlay_data) = overlay.get().as_deref() {
-                let x = match overlay_setting {
-                    Overlay::FL195 => overlay_data.clone().overlay_195,
-                    Overlay::FL105 => overlay_data.clone().overlay_105,
-                    Overlay::AtzDz => overlay_data.clone().overlay_atzdz,
+                // Get overlay data
+                let od = if let Some(overlay_setting) = settings().overlay {
+                    if let Some(overlay_data) = overlay.get().as_deref() {
+                        let x = match overlay_setting {
+                            Overlay::FL195 => overlay_data.clone().overlay_195,
+                            Overlay::FL105 => overlay_data.clone().overlay_105,
+                            Overlay::AtzDz => overlay_data.clone().overlay_atzdz,
+                        };
+                        x.unwrap_or("* Missing overlay data".to_string())
+                    } else {
+                        "* Overlay data not loaded".to_string()
+                    }
+                } else {
+                    "".to_string()
                 };
-                x.unwrap_or("* Missing overlay data".to_string())
-            } else {
-                "* Overlay data not loaded".to_string()
+
+                (oa + od.as_str(), "openair.txt")
+            }
+            OutputFormat::GeoJson => {
+                let (gj, errors) = geojson(&yaixm, &settings.get_untracked());
+                set_diagnostics.set(errors);
+                (gj, "airspace.geojson")
             }
-        } else {
-            "".to_string()
         };
 
         // Create download data
-        let blob = Blob::new((oa + od.as_str()).as_str());
+        let blob = Blob::new(data.as_str());
         let object_url = ObjectUrl::from(blob);
 
         let a = download_node_ref.get().unwrap();
-        a.set_download("openair.txt");
+        a.set_download(filename);
         a.set_href(&object_url);
         a.click();
     };
@@ -179,6 +239,21 @@ fn MainView(yaixm: Yaixm, overlay: LocalResource<OverlayData>) -> impl IntoView
             </Tabs>
         </div>
 
+        // Non-fatal warning when some volumes failed to parse
+        <div class="container block">
+            <div
+                class="notification is-warning mx-4"
+                class:is-hidden=move || diagnostics.get().is_empty()
+            >
+                {move || {
+                    let count = diagnostics.get().len();
+                    format!(
+                        "{count} airspace volume(s) could not be parsed and were skipped - see the About tab for details"
+                    )
+                }}
+            </div>
+        </div>
+
         <div class="container block">
             <div class="mx-4">
                 <button type="submit" class="button is-primary" on:click=download>
@@ -208,13 +283,15 @@ fn MainView(yaixm: Yaixm, overlay: LocalResource<OverlayData>) -> impl IntoView
     }
 }
 
-// Get YAIXM data from server
-async fn fetch_yaixm() -> Option<Yaixm> {
-    let result = Request::get("yaixm.json").send().await;
-    match result {
-        Ok(response) => response.json().await.ok(),
-        _ => None,
-    }
+// Get YAIXM data from server. Keeps hold of the error, rather than
+// collapsing it to None, so a malformed release still tells the user why
+async fn fetch_yaixm() -> Result<Yaixm, String> {
+    let response = Request::get("yaixm.json")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    response.json().await.map_err(|e| e.to_string())
 }
 
 // Get overlay data from server
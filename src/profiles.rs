@@ -0,0 +1,32 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+// Named, switchable Settings profiles (e.g. "Club default", "XC"), so a
+// club can keep several selections around without overwriting each other.
+use std::collections::BTreeMap;
+
+use crate::settings::Settings;
+
+// BTreeMap, rather than HashMap, so the profile list has a stable order
+// across renders and reloads
+pub type Profiles = BTreeMap<String, Settings>;
+
+pub const DEFAULT_PROFILE: &str = "Club default";
+
+pub fn default_profiles() -> Profiles {
+    let mut profiles = Profiles::new();
+    profiles.insert(DEFAULT_PROFILE.to_string(), Settings::default());
+    profiles
+}
@@ -0,0 +1,89 @@
+// Copyright 2024, Alan Sparrow
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// This program is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub enum ExtraType {
+    Rat,
+    Loa,
+    Wave,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum Overlay {
+    #[serde(rename = "fl195")]
+    FL195,
+    #[serde(rename = "fl105")]
+    FL105,
+    #[serde(rename = "atzdz")]
+    AtzDz,
+}
+
+// Output file format, selected in the Option tab
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum OutputFormat {
+    #[default]
+    OpenAir,
+    GeoJson,
+}
+
+// User selected airspace conversion options, persisted to local storage
+// and/or shared between devices
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Settings {
+    pub max_level: u16,
+    pub radio: bool,
+    pub north: bool,
+    pub atz: bool,
+    pub overlay: Option<Overlay>,
+    pub extra: HashMap<ExtraType, Vec<String>>,
+    pub format: OutputFormat,
+    // Simplify tessellated boundaries with Douglas-Peucker, for GPS units
+    // that cap the number of points per polygon
+    pub simplify: bool,
+    // Douglas-Peucker tolerance, in km
+    pub tolerance_km: f64,
+}
+
+impl Settings {
+    // Encode for the "s" query parameter of a shareable link. The browser's
+    // URLSearchParams takes care of percent-encoding the JSON.
+    pub fn to_query_value(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn from_query_value(value: &str) -> Option<Settings> {
+        serde_json::from_str(value).ok()
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            // Feet; no volume base is this high, so nothing is filtered out
+            max_level: u16::MAX,
+            radio: false,
+            north: false,
+            atz: true,
+            overlay: None,
+            extra: HashMap::new(),
+            format: OutputFormat::default(),
+            simplify: false,
+            tolerance_km: 0.5,
+        }
+    }
+}
@@ -13,7 +13,26 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 //
-use serde::Deserialize;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+// A YAIXM coordinate or radius that didn't parse, so the caller can skip
+// just the offending volume instead of aborting the whole conversion
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub feature: String,
+    pub value: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: \"{}\" - {}", self.feature, self.value, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
 
 #[derive(Clone, Copy, Deserialize, Debug, PartialEq)]
 pub enum IcaoClass {
@@ -26,7 +45,7 @@ pub enum IcaoClass {
     G,
 }
 
-#[derive(Clone, Deserialize, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub enum IcaoType {
     #[serde(rename = "ATZ")]
     Atz,
@@ -244,36 +263,110 @@ pub fn wave_names(yaixm: &Yaixm) -> Vec<String> {
 }
 
 // Convert lat/lon string to floating point degrees
-pub fn latlon_to_degrees(latlon: &str) -> (f64, f64) {
+pub fn latlon_to_degrees(feature: &str, latlon: &str) -> Result<(f64, f64), ParseError> {
+    let err = |message: &str| ParseError {
+        feature: feature.to_string(),
+        value: latlon.to_string(),
+        message: message.to_string(),
+    };
+
+    if latlon.len() < 16 {
+        return Err(err("latlon string too short"));
+    }
+    if !latlon.is_ascii() {
+        return Err(err("latlon string is not ASCII"));
+    }
+
     let bytes = latlon.as_bytes();
 
-    let mut deg: f64 = latlon[0..2].parse().unwrap();
-    let mut min: f64 = latlon[2..4].parse().unwrap();
-    let mut sec: f64 = latlon[4..6].parse().unwrap();
+    let deg: f64 = latlon[0..2].parse().map_err(|_| err("invalid latitude degrees"))?;
+    let min: f64 = latlon[2..4].parse().map_err(|_| err("invalid latitude minutes"))?;
+    let sec: f64 = latlon[4..6].parse().map_err(|_| err("invalid latitude seconds"))?;
     let mut lat = deg + min / 60.0 + sec / 3600.0;
     if bytes[6] == b'S' {
         lat = -lat;
+    } else if bytes[6] != b'N' {
+        return Err(err("invalid latitude hemisphere"));
     }
 
-    deg = latlon[8..11].parse().unwrap();
-    min = latlon[11..13].parse().unwrap();
-    sec = latlon[13..15].parse().unwrap();
+    let deg: f64 = latlon[8..11].parse().map_err(|_| err("invalid longitude degrees"))?;
+    let min: f64 = latlon[11..13].parse().map_err(|_| err("invalid longitude minutes"))?;
+    let sec: f64 = latlon[13..15].parse().map_err(|_| err("invalid longitude seconds"))?;
     let mut lon = deg + min / 60.0 + sec / 3600.0;
     if bytes[15] == b'W' {
         lon = -lon;
+    } else if bytes[15] != b'E' {
+        return Err(err("invalid longitude hemisphere"));
     }
 
-    (lat, lon)
+    Ok((lat, lon))
 }
 
 // Convert radius to floating point metres
-pub fn radius_to_metres(radius: &str) -> f64 {
-    let parts = radius.split(" ").collect::<Vec<&str>>();
-    let dist: f64 = parts[0].parse().unwrap();
-
-    if parts[1] == "nm" {
-        dist * 1852.0
-    } else {
-        dist * 1000.0
+pub fn radius_to_metres(feature: &str, radius: &str) -> Result<f64, ParseError> {
+    let err = |message: &str| ParseError {
+        feature: feature.to_string(),
+        value: radius.to_string(),
+        message: message.to_string(),
+    };
+
+    let parts = radius.split(' ').collect::<Vec<&str>>();
+    let [dist, unit] = parts[..] else {
+        return Err(err("expected '<distance> <unit>'"));
+    };
+
+    let dist: f64 = dist.parse().map_err(|_| err("invalid distance"))?;
+
+    match unit {
+        "nm" => Ok(dist * 1852.0),
+        "km" => Ok(dist * 1000.0),
+        _ => Err(err("unknown radius unit")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latlon_to_degrees_parses_valid_coordinate() {
+        let (lat, lon) = latlon_to_degrees("test", "513000N 0001500W").unwrap();
+        assert!((lat - 51.5).abs() < 1e-9);
+        assert!((lon - (-0.25)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn latlon_to_degrees_rejects_short_string() {
+        assert!(latlon_to_degrees("test", "513000N").is_err());
+    }
+
+    #[test]
+    fn latlon_to_degrees_rejects_non_ascii_without_panicking() {
+        // Byte length clears the 16-byte guard, but the leading character
+        // is a 2-byte UTF-8 sequence, so a byte-range slice would otherwise
+        // land mid-character and panic
+        let err = latlon_to_degrees("test", "é13000N 0001500W").unwrap_err();
+        assert!(err.message.contains("ASCII"));
+    }
+
+    #[test]
+    fn latlon_to_degrees_rejects_bad_hemisphere() {
+        assert!(latlon_to_degrees("test", "513000X 0001500W").is_err());
+    }
+
+    #[test]
+    fn radius_to_metres_converts_known_units() {
+        assert!((radius_to_metres("test", "10 nm").unwrap() - 18520.0).abs() < 1e-9);
+        assert!((radius_to_metres("test", "5 km").unwrap() - 5000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn radius_to_metres_rejects_unknown_unit() {
+        assert!(radius_to_metres("test", "5 mi").is_err());
+    }
+
+    #[test]
+    fn radius_to_metres_rejects_missing_unit() {
+        assert!(radius_to_metres("test", "5").is_err());
     }
 }